@@ -31,18 +31,30 @@ pub struct StandardScaler {
     pub ddof: f64,
     pub mean: f64,
     pub standard_deviation: f64,
-}
-
-/// It keeps track of the number of samples seen so far, to allow for
-/// incremental computation of mean and standard deviation.
-pub struct OnlineOptimizer {
+    // The sufficient statistics `OnlineOptimizer` needs to resume
+    // incremental fitting: the sample count feeding `mean`, and `M2`, the
+    // running sum of squared deviations from it (Welford/Chan). Carried on
+    // the scaler itself, rather than on the optimizer - see `OnlineOptimizer`'s
+    // docs for why - so `incremental_fit` is correct no matter which
+    // `OnlineOptimizer` instance is driving it.
     pub n_samples: u64,
+    pub m2: f64,
 }
 
-/// Initialize n_samples to 0.
+/// Drives `StandardScaler` fitting with a single-pass, Welford/Chan-style
+/// moment tracker.
+///
+/// The running sufficient statistics live on the `StandardScaler` itself, not
+/// here, despite this type's name: `incremental_fit` only has a `&mut self`
+/// receiver, and a stale or freshly-`Default`-ed `OnlineOptimizer` paired with
+/// an already-fitted `StandardScaler` would otherwise merge against the wrong
+/// history. Keeping `n_samples`/`mean`/`M2` on the scaler sidesteps that
+/// entirely, since the scaler being threaded through is always the real one.
+pub struct OnlineOptimizer;
+
 impl Default for OnlineOptimizer {
     fn default() -> Self {
-        Self { n_samples: 0 }
+        Self
     }
 }
 
@@ -63,14 +75,18 @@ where
         }
         // Compute relevant quantities
         let mean = inputs.mean_axis(Axis(0)).into_scalar();
-        let standard_deviation = inputs.std_axis(Axis(0), blueprint.ddof).into_scalar();
+        // M2 = population variance * n, i.e. the raw sum of squared deviations
+        let m2 = inputs.std_axis(Axis(0), 0.).into_scalar().powi(2) * (inputs.len() as f64);
         // Initialize n_samples using the array length
-        self.n_samples = inputs.len() as u64;
+        let n_samples = inputs.len() as u64;
         // Return new, tuned scaler
+        let standard_deviation = (m2 / (n_samples as f64 - blueprint.ddof)).sqrt();
         Ok(StandardScaler {
             ddof: blueprint.ddof,
             mean,
             standard_deviation,
+            n_samples,
+            m2,
         })
     }
 }
@@ -94,26 +110,26 @@ where
         // Compute relevant quantities for the new batch
         let batch_n_samples = inputs.len();
         let batch_mean = inputs.mean_axis(Axis(0)).into_scalar();
-        let batch_std = inputs.std_axis(Axis(0), transformer.ddof).into_scalar();
-
-        // Update
-        let mean_delta = batch_mean - transformer.mean;
-        let new_n_samples = self.n_samples + (batch_n_samples as u64);
-        let new_mean =
-            transformer.mean + mean_delta * (batch_n_samples as f64) / (new_n_samples as f64);
-        let new_std = transformer.standard_deviation
-            + batch_std
-            + mean_delta.powi(2) * (self.n_samples as f64) * (batch_n_samples as f64)
+        let batch_m2 = inputs.std_axis(Axis(0), 0.).into_scalar().powi(2) * (batch_n_samples as f64);
+
+        // Chan's parallel merge of group A (the scaler's own running moments,
+        // read back from `transformer`, not `self`) and group B (this batch).
+        let delta = batch_mean - transformer.mean;
+        let new_n_samples = transformer.n_samples + (batch_n_samples as u64);
+        let new_mean = transformer.mean + delta * (batch_n_samples as f64) / (new_n_samples as f64);
+        let new_m2 = transformer.m2
+            + batch_m2
+            + delta.powi(2) * (transformer.n_samples as f64) * (batch_n_samples as f64)
                 / (new_n_samples as f64);
 
-        // Update n_samples
-        self.n_samples = new_n_samples;
-
         // Return tuned scaler
+        let standard_deviation = (new_m2 / (new_n_samples as f64 - transformer.ddof)).sqrt();
         Ok(StandardScaler {
             ddof: transformer.ddof,
             mean: new_mean,
-            standard_deviation: new_std,
+            standard_deviation,
+            n_samples: new_n_samples,
+            m2: new_m2,
         })
     }
 }
@@ -144,6 +160,7 @@ where
     S: Data<Elem = f64>,
 {
     type Error = ScalingError;
+    type Inverted = Output;
 
     fn transform(&self, inputs: &Input<S>) -> Result<Output, Self::Error>
     where
@@ -5,49 +5,99 @@ extern crate rand;
 #[macro_use]
 extern crate derive_more;
 
+use crate::pipeline::{PipelineBlueprint, PipelineFit};
+use crate::power_transformer::{Config as PowerConfig, Mle, PowerTransformError, PowerTransformer};
 use crate::standard_scaler::{Config, OnlineOptimizer, ScalingError, StandardScaler};
 use linfa::{Fit, IncrementalFit, Transformer};
-use ndarray::{stack, Array1, ArrayBase, Axis, Data, Ix1};
+use ndarray::{stack, Array2, ArrayBase, Axis, Data, Ix2};
 use ndarray_rand::RandomExt;
-use rand::distributions::Uniform;
+use rand::distributions::{Exp1, Uniform};
 
+mod optimizer;
+mod pipeline;
+mod power_transformer;
 mod standard_scaler;
 
-fn generate_batch(n_samples: usize) -> (Array1<f64>, Array1<f64>) {
+fn generate_batch(n_samples: usize, n_features: usize) -> (Array2<f64>, Array2<f64>) {
     let distribution = Uniform::new(0., 10.);
-    let x = Array1::random(n_samples, distribution);
-    let y = Array1::random(n_samples, distribution);
+    let x = Array2::random((n_samples, n_features), distribution);
+    let y = Array2::random((n_samples, 1), distribution);
     (x, y)
 }
 
-fn check<S>(scaler: &StandardScaler, x: &ArrayBase<S, Ix1>) -> Result<(), ScalingError>
+fn check<S>(scaler: &StandardScaler, x: &ArrayBase<S, Ix2>) -> Result<(), ScalingError>
 where
     S: Data<Elem = f64>,
 {
-    let old_batch_mean = x.mean_axis(Axis(0)).into_scalar();
-    let new_batch_mean = scaler.transform(&x)?.mean_axis(Axis(0)).into_scalar();
+    let old_batch_mean = x.mean_axis(Axis(0));
+    let new_batch_mean = scaler.transform(&x)?.mean_axis(Axis(0));
     println!(
-        "The mean.\nBefore scaling: {:?}\nAfter scaling: {:?}\n",
+        "The per-column mean.\nBefore scaling: {:?}\nAfter scaling: {:?}\n",
         old_batch_mean, new_batch_mean
     );
     Ok(())
 }
 
+fn check_power_transform(transformer: &PowerTransformer, x: &Array2<f64>) -> Result<(), PowerTransformError> {
+    let transformed = transformer.transform(x)?;
+    println!(
+        "Power transform λ: {:?}\nSkewed input sample: {:?}\nTransformed sample: {:?}\n",
+        transformer.lambda,
+        x.row(0),
+        transformed.row(0)
+    );
+    Ok(())
+}
+
 /// Run it with: cargo run --example running_mean
 fn main() -> Result<(), ScalingError> {
     let n_samples = 20;
-    let (x, y) = generate_batch(n_samples);
+    let n_features = 3;
+    let (x, y) = generate_batch(n_samples, n_features);
 
     let mut optimizer = OnlineOptimizer::default();
     let standard_scaler = optimizer.fit(&x, &y, Config::default())?;
 
     check(&standard_scaler, &x)?;
 
-    let (x2, y2) = generate_batch(n_samples);
+    let (x2, y2) = generate_batch(n_samples, n_features);
     let standard_scaler = optimizer.incremental_fit(&x2, &y2, standard_scaler)?;
 
     let whole_x = stack(Axis(0), &[x.view(), x2.view()]).expect("Failed to stack arrays");
     check(&standard_scaler, &whole_x)?;
 
+    let skewed_x = Array2::random((n_samples, n_features), Exp1);
+    let mut mle = Mle::default();
+    let power_transformer = mle
+        .fit(&skewed_x, &y, PowerConfig::default())
+        .expect("Failed to fit PowerTransformer");
+    check_power_transform(&power_transformer, &skewed_x).expect("Failed to check power transform");
+
+    // A PowerTransformer (without its own standardization) feeding a StandardScaler:
+    // two independently-defined stages, fit and run as a single Pipeline.
+    let blueprint = PipelineBlueprint::new(
+        PowerConfig {
+            standardize: false,
+            ..PowerConfig::default()
+        },
+        Config::default(),
+    );
+    let mut pipeline_fit = PipelineFit::new(Mle::default(), OnlineOptimizer::default());
+    let power_then_scale = pipeline_fit
+        .fit(&skewed_x, &y, blueprint)
+        .expect("Failed to fit Pipeline");
+    let piped = power_then_scale
+        .transform(&skewed_x)
+        .expect("Failed to run Pipeline");
+    println!("Power transform + standard scaling, first row: {:?}", piped.row(0));
+
+    let scaled_x = standard_scaler.transform(&x)?;
+    let round_tripped = standard_scaler.inverse_transform(&scaled_x)?;
+    println!(
+        "Round-tripped through StandardScaler, first row.\nOriginal: {:?}\nRecovered: {:?}\n",
+        x.row(0),
+        round_tripped.row(0)
+    );
+
     Ok(())
 }
@@ -0,0 +1,41 @@
+use crate::optimizer::{Objective, Optimizer};
+
+/// Fixed-step gradient descent: `x ← x − η·g(x)`, iterated until the step
+/// shrinks below `tolerance` or `max_iterations` is reached.
+///
+/// If the gradient (or the resulting step) comes out non-finite, iteration
+/// stops and the last finite `x` is returned rather than letting
+/// `NaN`/`inf` propagate.
+pub struct GradientDescent {
+    pub learning_rate: f64,
+    pub max_iterations: usize,
+    pub tolerance: f64,
+}
+
+impl Default for GradientDescent {
+    fn default() -> Self {
+        Self {
+            learning_rate: 0.01,
+            max_iterations: 1000,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+impl Optimizer for GradientDescent {
+    fn minimize<O: Objective>(&self, objective: &O, x0: f64) -> f64 {
+        let mut x = x0;
+        for _ in 0..self.max_iterations {
+            let step = self.learning_rate * objective.gradient(x);
+            let next_x = x - step;
+            if !next_x.is_finite() {
+                break;
+            }
+            x = next_x;
+            if step.abs() < self.tolerance {
+                break;
+            }
+        }
+        x
+    }
+}
@@ -0,0 +1,16 @@
+mod gradient_descent;
+mod newton;
+mod objective;
+
+pub use gradient_descent::GradientDescent;
+pub use newton::Newton;
+pub use objective::Objective;
+
+/// Drives the search for the `x` minimizing an `Objective`, starting from `x0`.
+///
+/// This is what lets transformers whose parameters have no closed form (e.g.
+/// `PowerTransformer`'s λ) be trained generically, instead of each one
+/// hand-rolling its own search loop.
+pub trait Optimizer {
+    fn minimize<O: Objective>(&self, objective: &O, x0: f64) -> f64;
+}
@@ -0,0 +1,56 @@
+use crate::optimizer::{Objective, Optimizer};
+
+// Below this magnitude the Hessian is treated as singular.
+const SINGULAR_HESSIAN_THRESHOLD: f64 = 1e-10;
+
+/// Newton's method: `x ← x − H⁻¹(x)·g(x)`, iterated until `|Δx|` drops below
+/// `tolerance` or `max_iterations` is reached.
+///
+/// When the Hessian is singular (or unavailable) at the current `x`, falls
+/// back to a fixed-step gradient descent move for that iteration rather than
+/// dividing by (near) zero.
+///
+/// On a flat or non-concave objective, the gradient/Hessian (or the step
+/// computed from them) can come out non-finite - e.g. `ln(0)` on a
+/// near-constant column. Rather than let that `NaN`/`inf` propagate into `x`,
+/// iteration stops and the last finite `x` is returned.
+pub struct Newton {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub fallback_learning_rate: f64,
+}
+
+impl Default for Newton {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-6,
+            max_iterations: 100,
+            fallback_learning_rate: 0.01,
+        }
+    }
+}
+
+impl Optimizer for Newton {
+    fn minimize<O: Objective>(&self, objective: &O, x0: f64) -> f64 {
+        let mut x = x0;
+        for _ in 0..self.max_iterations {
+            let gradient = objective.gradient(x);
+            if !gradient.is_finite() {
+                break;
+            }
+            let step = match objective.hessian(x) {
+                Some(hessian) if hessian.abs() > SINGULAR_HESSIAN_THRESHOLD => gradient / hessian,
+                _ => self.fallback_learning_rate * gradient,
+            };
+            let next_x = x - step;
+            if !next_x.is_finite() {
+                break;
+            }
+            x = next_x;
+            if step.abs() < self.tolerance {
+                break;
+            }
+        }
+        x
+    }
+}
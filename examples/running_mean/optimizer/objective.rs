@@ -0,0 +1,17 @@
+/// A scalar objective function to be minimized.
+///
+/// `gradient` is required; `hessian` is optional - solvers that don't need
+/// second-order information (e.g. gradient descent) simply ignore it, and
+/// solvers that do (e.g. Newton) fall back gracefully when it's `None`.
+pub trait Objective {
+    fn value(&self, x: f64) -> f64;
+
+    fn gradient(&self, x: f64) -> f64;
+
+    /// `None` when the Hessian is unavailable at `x`. A (near-)singular value
+    /// may still come back as `Some`: `Newton` checks its magnitude before
+    /// inverting it.
+    fn hessian(&self, _x: f64) -> Option<f64> {
+        None
+    }
+}
@@ -0,0 +1,21 @@
+use std::error::Error;
+use std::fmt;
+
+/// Wraps whichever stage of the `Pipeline` failed, without losing the
+/// original error.
+#[derive(Debug)]
+pub enum PipelineError<E1, E2> {
+    First(E1),
+    Second(E2),
+}
+
+impl<E1: fmt::Display, E2: fmt::Display> fmt::Display for PipelineError<E1, E2> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipelineError::First(e) => write!(f, "first stage failed: {}", e),
+            PipelineError::Second(e) => write!(f, "second stage failed: {}", e),
+        }
+    }
+}
+
+impl<E1: Error, E2: Error> Error for PipelineError<E1, E2> {}
@@ -0,0 +1,49 @@
+use crate::pipeline::{Pipeline, PipelineBlueprint, PipelineError};
+use linfa::{Blueprint, Fit, Transformer};
+
+/// Fits a `Pipeline` stage by stage: `first` is fit on `inputs`, then `second`
+/// is fit on whatever `first` transforms `inputs` into.
+pub struct PipelineFit<FA, FB> {
+    pub first: FA,
+    pub second: FB,
+}
+
+impl<FA, FB> PipelineFit<FA, FB> {
+    pub fn new(first: FA, second: FB) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<FA, FB, BA, BB, I, M, O> Fit<PipelineBlueprint<BA, BB>, I, O> for PipelineFit<FA, FB>
+where
+    BA: Blueprint<I, M>,
+    BB: Blueprint<M, O>,
+    M: Default,
+    FA: Fit<BA, I, M, Error = <BA::Transformer as Transformer<I, M>>::Error>,
+    FB: Fit<BB, M, O, Error = <BB::Transformer as Transformer<M, O>>::Error>,
+{
+    type Error = PipelineError<FA::Error, FB::Error>;
+
+    fn fit(
+        &mut self,
+        inputs: &I,
+        targets: &O,
+        blueprint: PipelineBlueprint<BA, BB>,
+    ) -> Result<Pipeline<BA::Transformer, BB::Transformer>, Self::Error> {
+        // `first` fits against the intermediate space `M`, not the pipeline's
+        // final `O` - it has no `M`-typed labels to offer, but every `Fit`
+        // impl in this codebase is unsupervised and ignores `targets`
+        // entirely, so a throwaway `M::default()` satisfies the signature
+        // without pretending we have real ones.
+        let first = self
+            .first
+            .fit(inputs, &M::default(), blueprint.first)
+            .map_err(PipelineError::First)?;
+        let intermediate = first.transform(inputs).map_err(PipelineError::First)?;
+        let second = self
+            .second
+            .fit(&intermediate, targets, blueprint.second)
+            .map_err(PipelineError::Second)?;
+        Ok(Pipeline::new(first, second))
+    }
+}
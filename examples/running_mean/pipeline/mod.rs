@@ -0,0 +1,103 @@
+mod error;
+mod fit;
+
+pub use error::PipelineError;
+pub use fit::PipelineFit;
+
+use linfa::{Blueprint, Transformer};
+
+/// Chains two stages together: the output of `first` is fed as the input of
+/// `second`.
+///
+/// Longer chains are built by nesting: `Pipeline::new(a, b).then(c)` is a
+/// `Pipeline<Pipeline<A, B>, C>`, the same trick `Iterator::chain` uses to
+/// stack adapters without a heterogeneous collection.
+pub struct Pipeline<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Pipeline<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Appends a further stage, returning a new, longer pipeline.
+    pub fn then<C>(self, next: C) -> Pipeline<Self, C> {
+        Pipeline::new(self, next)
+    }
+}
+
+impl<A, B, I, M, O> Transformer<I, O> for Pipeline<A, B>
+where
+    A: Transformer<I, M>,
+    B: Transformer<M, O>,
+{
+    type Error = PipelineError<A::Error, B::Error>;
+
+    // Inverting a `Pipeline` bottoms out at `first`'s own `Inverted`: `second`
+    // is only ever inverted as far back as `M`, `first`'s forward output.
+    type Inverted = A::Inverted;
+
+    fn transform(&self, inputs: &I) -> Result<O, Self::Error> {
+        let intermediate = self.first.transform(inputs).map_err(PipelineError::First)?;
+        self.second
+            .transform(&intermediate)
+            .map_err(PipelineError::Second)
+    }
+
+    // `second.inverse_transform` only lands back on `M` - the type `first`
+    // knows how to invert further - when `B::Inverted = M`. That's not true
+    // of every `B`, so it isn't part of this impl's bounds (a forward-only
+    // `Pipeline` shouldn't have to satisfy it just to be a `Transformer`).
+    // The inherent `Pipeline::inverse_transform` below picks up the slack
+    // whenever that extra bound does hold.
+}
+
+impl<A, B, I, M, O> Pipeline<A, B>
+where
+    A: Transformer<I, M>,
+    B: Transformer<M, O, Inverted = M>,
+{
+    /// Inverts the whole pipeline: `second` is inverted back to `M`, then
+    /// `first` is inverted the rest of the way back to `A::Inverted`.
+    ///
+    /// Only available when `second`'s `Inverted` is `M` - `first`'s forward
+    /// output - which isn't needed for `transform`, so it isn't required by
+    /// the `Transformer` impl above; pipelines built only to run forward
+    /// don't have to satisfy it.
+    pub fn inverse_transform(
+        &self,
+        outputs: &O,
+    ) -> Result<A::Inverted, PipelineError<A::Error, B::Error>> {
+        let intermediate = self
+            .second
+            .inverse_transform(outputs)
+            .map_err(PipelineError::Second)?;
+        self.first
+            .inverse_transform(&intermediate)
+            .map_err(PipelineError::First)
+    }
+}
+
+/// A `Blueprint` pair: `first` forges the first stage of the `Pipeline`,
+/// `second` forges the second stage, fed with whatever the first stage
+/// produces.
+pub struct PipelineBlueprint<BA, BB> {
+    pub first: BA,
+    pub second: BB,
+}
+
+impl<BA, BB> PipelineBlueprint<BA, BB> {
+    pub fn new(first: BA, second: BB) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<BA, BB, I, M, O> Blueprint<I, O> for PipelineBlueprint<BA, BB>
+where
+    BA: Blueprint<I, M>,
+    BB: Blueprint<M, O>,
+{
+    type Transformer = Pipeline<BA::Transformer, BB::Transformer>;
+}
@@ -0,0 +1,36 @@
+use crate::power_transformer::{Input, Output, PowerTransformer};
+use linfa::Blueprint;
+use ndarray::Data;
+
+/// Which family of power transform to fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    /// `y = (x^λ − 1)/λ` for `λ≠0`, `ln(x)` for `λ=0`. Only defined for `x > 0`.
+    BoxCox,
+    /// Handles negative inputs too, at the cost of a slightly less interpretable formula.
+    YeoJohnson,
+}
+
+pub struct Config {
+    pub method: Method,
+    // Whether the transformed columns should be standardized to zero mean,
+    // unit variance after the power transform is applied. Mirrors sklearn's
+    // `PowerTransformer(standardize=True)` default.
+    pub standardize: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            method: Method::YeoJohnson,
+            standardize: true,
+        }
+    }
+}
+
+impl<S> Blueprint<Input<S>, Output> for Config
+where
+    S: Data<Elem = f64>,
+{
+    type Transformer = PowerTransformer;
+}
@@ -0,0 +1,28 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why fitting or applying a `PowerTransformer` failed.
+#[derive(Debug, Eq, PartialEq)]
+pub enum PowerTransformError {
+    EmptyInput,
+    // Box-Cox is only defined for strictly positive data.
+    NonPositiveData,
+    // The number of columns doesn't match the number of fitted λs.
+    DimensionMismatch,
+}
+
+impl fmt::Display for PowerTransformError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PowerTransformError::EmptyInput => write!(f, "input has no rows"),
+            PowerTransformError::NonPositiveData => {
+                write!(f, "Box-Cox requires strictly positive data")
+            }
+            PowerTransformError::DimensionMismatch => {
+                write!(f, "column count doesn't match the number of fitted λs")
+            }
+        }
+    }
+}
+
+impl Error for PowerTransformError {}
@@ -0,0 +1,131 @@
+use crate::optimizer::{Newton, Objective, Optimizer};
+use crate::power_transformer::transformer::{box_cox, yeo_johnson};
+use crate::power_transformer::{Config, Input, Method, Output, PowerTransformError, PowerTransformer};
+use linfa::{Fit, Transformer};
+use ndarray::{Array1, ArrayView1, Axis, Data};
+
+// λ is clamped to this interval, as recommended by sklearn's `PowerTransformer`.
+const LAMBDA_MIN: f64 = -5.;
+const LAMBDA_MAX: f64 = 5.;
+
+// Step used to approximate the likelihood's gradient/Hessian by central
+// finite differences: there's no closed form for either.
+const FINITE_DIFFERENCE_STEP: f64 = 1e-4;
+
+/// Fits a `PowerTransformer` by picking, per column, the λ that maximizes the
+/// profile log-likelihood of the transformed data under a Gaussian model.
+///
+/// There's no closed form for λ, so it's found by driving a `Newton`
+/// optimizer over the negative log-likelihood.
+pub struct Mle;
+
+impl Default for Mle {
+    fn default() -> Self {
+        Self
+    }
+}
+
+/// `s_i = ln(x_i)` (Box-Cox) or `sign(x_i)·ln(|x_i|+1)` (Yeo-Johnson).
+fn log_jacobian_term(x: f64, method: Method) -> f64 {
+    match method {
+        Method::BoxCox => x.ln(),
+        Method::YeoJohnson => x.signum() * (x.abs() + 1.).ln(),
+    }
+}
+
+/// `ℓ(λ) = −(n/2)·ln(σ²(λ)) + (λ−1)·Σ s_i`
+fn profile_log_likelihood(column: &ArrayView1<f64>, lambda: f64, method: Method) -> f64 {
+    let n = column.len() as f64;
+    let transformed: Array1<f64> = column.mapv(|x| match method {
+        Method::BoxCox => box_cox(x, lambda),
+        Method::YeoJohnson => yeo_johnson(x, lambda),
+    });
+    let variance = transformed.var_axis(Axis(0), 0.).into_scalar();
+    let log_jacobian: f64 = column.iter().map(|&x| log_jacobian_term(x, method)).sum();
+    -0.5 * n * variance.ln() + (lambda - 1.) * log_jacobian
+}
+
+/// The negative profile log-likelihood of a single column, as an `Objective`
+/// to minimize. Its gradient and Hessian are approximated by central finite
+/// differences, since `profile_log_likelihood` has no closed-form derivative.
+struct NegativeLogLikelihood<'a> {
+    column: ArrayView1<'a, f64>,
+    method: Method,
+}
+
+impl<'a> Objective for NegativeLogLikelihood<'a> {
+    fn value(&self, lambda: f64) -> f64 {
+        -profile_log_likelihood(&self.column, lambda, self.method)
+    }
+
+    fn gradient(&self, lambda: f64) -> f64 {
+        let h = FINITE_DIFFERENCE_STEP;
+        (self.value(lambda + h) - self.value(lambda - h)) / (2. * h)
+    }
+
+    fn hessian(&self, lambda: f64) -> Option<f64> {
+        let h = FINITE_DIFFERENCE_STEP;
+        Some((self.value(lambda + h) - 2. * self.value(lambda) + self.value(lambda - h)) / (h * h))
+    }
+}
+
+/// Drives a `Newton` optimizer to the λ maximizing `profile_log_likelihood`,
+/// then clamps it to `[LAMBDA_MIN, LAMBDA_MAX]`.
+///
+/// `Newton` bails out to the last finite iterate if the likelihood goes
+/// non-finite along the way (e.g. a near-constant transformed column), so the
+/// clamp below only ever bounds an already-finite result, never masking a
+/// `NaN`.
+fn fit_lambda(column: &ArrayView1<f64>, method: Method) -> f64 {
+    let objective = NegativeLogLikelihood {
+        column: column.view(),
+        method,
+    };
+    let lambda = Newton::default().minimize(&objective, 1.);
+    lambda.max(LAMBDA_MIN).min(LAMBDA_MAX)
+}
+
+impl<S> Fit<Config, Input<S>, Output> for Mle
+where
+    S: Data<Elem = f64>,
+{
+    type Error = PowerTransformError;
+
+    fn fit(
+        &mut self,
+        inputs: &Input<S>,
+        _targets: &Output,
+        blueprint: Config,
+    ) -> Result<PowerTransformer, Self::Error> {
+        if inputs.nrows() == 0 {
+            return Err(PowerTransformError::EmptyInput);
+        }
+        if blueprint.method == Method::BoxCox && inputs.iter().any(|&x| x <= 0.) {
+            return Err(PowerTransformError::NonPositiveData);
+        }
+
+        let lambda = Array1::from_iter(
+            (0..inputs.ncols()).map(|j| fit_lambda(&inputs.column(j), blueprint.method)),
+        );
+
+        let unstandardized = PowerTransformer {
+            method: blueprint.method,
+            lambda: lambda.clone(),
+            mean: None,
+            std: None,
+        };
+        if !blueprint.standardize {
+            return Ok(unstandardized);
+        }
+
+        let transformed = unstandardized.transform(inputs)?;
+        let mean = transformed.mean_axis(Axis(0));
+        let std = transformed.std_axis(Axis(0), 0.);
+        Ok(PowerTransformer {
+            method: blueprint.method,
+            lambda,
+            mean: Some(mean),
+            std: Some(std),
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use ndarray::{Array2, ArrayBase, Ix2};
+
+/// Short-hand notations
+type Input<S> = ArrayBase<S, Ix2>;
+type Output = Array2<f64>;
+
+mod config;
+mod error;
+mod fit;
+mod transformer;
+
+pub use config::{Config, Method};
+pub use error::PowerTransformError;
+pub use fit::Mle;
+pub use transformer::PowerTransformer;
@@ -0,0 +1,122 @@
+use crate::power_transformer::{Input, Method, Output, PowerTransformError};
+use linfa::Transformer;
+use ndarray::{Array1, Array2, Data};
+
+/// Applies a Box-Cox or Yeo-Johnson power transform, column by column, to make
+/// skewed data look more Gaussian.
+pub struct PowerTransformer {
+    pub method: Method,
+    // One entry per column.
+    pub lambda: Array1<f64>,
+    // Set when the transformer was fitted with `standardize: true`: the
+    // transformed columns are additionally mapped to zero mean, unit variance.
+    pub mean: Option<Array1<f64>>,
+    pub std: Option<Array1<f64>>,
+}
+
+/// `y = (x^λ − 1)/λ` for `λ≠0`, `ln(x)` for `λ=0`.
+pub(crate) fn box_cox(x: f64, lambda: f64) -> f64 {
+    if lambda == 0. {
+        x.ln()
+    } else {
+        (x.powf(lambda) - 1.) / lambda
+    }
+}
+
+/// For `x≥0`: `((x+1)^λ − 1)/λ` (`λ≠0`) or `ln(x+1)` (`λ=0`).
+/// For `x<0`: `−((−x+1)^{2−λ} − 1)/(2−λ)` (`λ≠2`) or `−ln(−x+1)` (`λ=2`).
+pub(crate) fn yeo_johnson(x: f64, lambda: f64) -> f64 {
+    if x >= 0. {
+        if lambda == 0. {
+            (x + 1.).ln()
+        } else {
+            ((x + 1.).powf(lambda) - 1.) / lambda
+        }
+    } else if lambda == 2. {
+        -(-x + 1.).ln()
+    } else {
+        -((-x + 1.).powf(2. - lambda) - 1.) / (2. - lambda)
+    }
+}
+
+/// Inverts `box_cox`.
+pub(crate) fn inverse_box_cox(y: f64, lambda: f64) -> f64 {
+    if lambda == 0. {
+        y.exp()
+    } else {
+        (y * lambda + 1.).powf(1. / lambda)
+    }
+}
+
+/// Inverts `yeo_johnson`. `y`'s sign determines which branch was used
+/// forward, the same way `x`'s sign does.
+pub(crate) fn inverse_yeo_johnson(y: f64, lambda: f64) -> f64 {
+    if y >= 0. {
+        if lambda == 0. {
+            y.exp() - 1.
+        } else {
+            (y * lambda + 1.).powf(1. / lambda) - 1.
+        }
+    } else if lambda == 2. {
+        1. - (-y).exp()
+    } else {
+        1. - (1. - y * (2. - lambda)).powf(1. / (2. - lambda))
+    }
+}
+
+impl<S> Transformer<Input<S>, Output> for PowerTransformer
+where
+    S: Data<Elem = f64>,
+{
+    type Error = PowerTransformError;
+
+    // `inverse_transform` always hands back a freshly-allocated `Output`,
+    // regardless of what storage `S` was used to call `transform`: there's no
+    // single `S` to resurrect once the original array is gone.
+    type Inverted = Output;
+
+    fn transform(&self, inputs: &Input<S>) -> Result<Output, Self::Error> {
+        if inputs.ncols() != self.lambda.len() {
+            return Err(PowerTransformError::DimensionMismatch);
+        }
+        if self.method == Method::BoxCox && inputs.iter().any(|&x| x <= 0.) {
+            return Err(PowerTransformError::NonPositiveData);
+        }
+
+        let mut output = Array2::zeros(inputs.raw_dim());
+        for (j, &lambda) in self.lambda.iter().enumerate() {
+            let transformed = inputs.column(j).mapv(|x| match self.method {
+                Method::BoxCox => box_cox(x, lambda),
+                Method::YeoJohnson => yeo_johnson(x, lambda),
+            });
+            output.column_mut(j).assign(&transformed);
+        }
+
+        Ok(match (&self.mean, &self.std) {
+            (Some(mean), Some(std)) => (output - mean) / std,
+            _ => output,
+        })
+    }
+
+    fn inverse_transform(&self, outputs: &Output) -> Result<Self::Inverted, Self::Error> {
+        if outputs.ncols() != self.lambda.len() {
+            return Err(PowerTransformError::DimensionMismatch);
+        }
+
+        let destandardized = match (&self.mean, &self.std) {
+            (Some(mean), Some(std)) => outputs * std + mean,
+            _ => outputs.clone(),
+        };
+
+        let mut original = Array2::zeros(destandardized.raw_dim());
+        for (j, &lambda) in self.lambda.iter().enumerate() {
+            let inverted = destandardized.column(j).mapv(|y| match self.method {
+                Method::BoxCox => inverse_box_cox(y, lambda),
+                Method::YeoJohnson => inverse_yeo_johnson(y, lambda),
+            });
+            original.column_mut(j).assign(&inverted);
+        }
+
+        Ok(original)
+    }
+}
@@ -7,19 +7,29 @@ pub struct Config {
     // With ddof = 1, you get the sample standard deviation
     // With ddof = 0, you get the population standard deviation
     pub ddof: f64,
+    // Where each column should be centered after scaling.
+    // Defaults to 0., i.e. standard scaling.
+    pub target_mean: f64,
+    // What each column's standard deviation should be after scaling.
+    // Defaults to 1., i.e. standard scaling.
+    pub target_scale: f64,
 }
 
-/// Defaults to computing the sample standard deviation.
+/// Defaults to computing the sample standard deviation and mapping
+/// every column to zero mean, unit variance.
 impl Default for Config {
     fn default() -> Self {
-        Self { ddof: 1. }
+        Self {
+            ddof: 1.,
+            target_mean: 0.,
+            target_scale: 1.,
+        }
     }
 }
 
 impl<S> Blueprint<Input<S>, Output> for Config
-    where
-        S: Data<Elem = f64>,
+where
+    S: Data<Elem = f64>,
 {
     type Transformer = StandardScaler;
 }
-
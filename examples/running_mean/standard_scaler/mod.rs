@@ -1,8 +1,8 @@
-use ndarray::{Array1, ArrayBase, Ix1};
+use ndarray::{Array2, ArrayBase, Ix2};
 
 /// Short-hand notations
-type Input<S> = ArrayBase<S, Ix1>;
-type Output = Array1<f64>;
+type Input<S> = ArrayBase<S, Ix2>;
+type Output = Array2<f64>;
 
 mod config;
 mod error;
@@ -1,20 +1,41 @@
 use crate::standard_scaler::{Config, Input, Output, ScalingError, StandardScaler};
 use linfa::{Fit, IncrementalFit};
-use ndarray::{Axis, Data};
+use ndarray::{Array1, Axis, Data};
 
-/// It keeps track of the number of samples seen so far, to allow for
-/// incremental computation of mean and standard deviation.
-pub struct OnlineOptimizer {
-    pub n_samples: u64,
-}
+/// Drives `StandardScaler` fitting with a single-pass, Welford/Chan-style
+/// moment tracker.
+///
+/// Deliberate deviation from "expose M2 on OnlineOptimizer": the running
+/// sufficient statistics (`n_samples`, `mean`, `M2`, the running sum of
+/// squared deviations from `mean`) live on the `StandardScaler` itself, not on
+/// the optimizer. `incremental_fit` only takes `&mut self`, so an
+/// `OnlineOptimizer` that carried its own copy of these would go stale (or be
+/// flat-out wrong from a fresh `Default`) the moment it's paired with a
+/// `StandardScaler` it didn't fit itself. Keeping them on the scaler makes
+/// `incremental_fit` correct no matter which `OnlineOptimizer` instance is
+/// driving it, as long as the right `StandardScaler` is threaded through.
+/// Reported variance is `M2 / (n_samples - ddof)`.
+///
+/// Tracking `M2` directly (rather than re-deriving variance from scratch on
+/// every batch) keeps the merge numerically stable and well-defined even for
+/// single-sample batches.
+pub struct OnlineOptimizer;
 
-/// Initialize n_samples to 0.
 impl Default for OnlineOptimizer {
     fn default() -> Self {
-        Self { n_samples: 0 }
+        Self
     }
 }
 
+/// `M2` of a batch, computed directly: the population variance (`ddof=0`)
+/// scaled back up by the number of samples.
+fn batch_m2<S>(inputs: &Input<S>) -> Array1<f64>
+where
+    S: Data<Elem = f64>,
+{
+    inputs.var_axis(Axis(0), 0.) * (inputs.nrows() as f64)
+}
+
 impl<S> Fit<Config, Input<S>, Output> for OnlineOptimizer
 where
     S: Data<Elem = f64>,
@@ -27,19 +48,23 @@ where
         _targets: &Output,
         blueprint: Config,
     ) -> Result<StandardScaler, Self::Error> {
-        if inputs.len() == 0 {
+        if inputs.nrows() == 0 {
             return Err(ScalingError {});
         }
-        // Compute relevant quantities
-        let mean = inputs.mean_axis(Axis(0)).into_scalar();
-        let standard_deviation = inputs.std_axis(Axis(0), blueprint.ddof).into_scalar();
-        // Initialize n_samples using the array length
-        self.n_samples = inputs.len() as u64;
-        // Return new, tuned scaler
+        // Initialize the running moments from this batch, one entry per column
+        let mean = inputs.mean_axis(Axis(0));
+        let m2 = batch_m2(inputs);
+        let n_samples = inputs.nrows() as u64;
+
+        let standard_deviation = (&m2 / (n_samples as f64 - blueprint.ddof)).mapv(f64::sqrt);
         Ok(StandardScaler {
             ddof: blueprint.ddof,
+            target_mean: blueprint.target_mean,
+            target_scale: blueprint.target_scale,
             mean,
             standard_deviation,
+            n_samples,
+            m2,
         })
     }
 }
@@ -56,38 +81,43 @@ where
         _targets: &Output,
         transformer: StandardScaler,
     ) -> Result<StandardScaler, Self::Error> {
-        if inputs.len() == 0 {
+        if inputs.nrows() == 0 {
             // Nothing to be done
             return Ok(transformer);
         }
+        if inputs.ncols() != transformer.mean.len() {
+            return Err(ScalingError {});
+        }
 
         let ddof = transformer.ddof;
 
-        // Compute relevant quantities for the new batch
-        let batch_n_samples = inputs.len();
-        let batch_mean = inputs.mean_axis(Axis(0)).into_scalar();
-        let batch_std = inputs.std_axis(Axis(0), ddof).into_scalar();
+        // Moments of the new batch, one entry per column
+        let batch_n_samples = inputs.nrows();
+        let batch_mean = inputs.mean_axis(Axis(0));
+        let batch_m2 = batch_m2(inputs);
 
-        // Update
-        let mean_delta = batch_mean - transformer.mean;
-        let new_n_samples = self.n_samples + (batch_n_samples as u64);
-        let new_mean =
-            transformer.mean + mean_delta * (batch_n_samples as f64) / (new_n_samples as f64);
-        let new_std = ((transformer.standard_deviation.powi(2) * (self.n_samples as f64 - ddof)
-            + batch_std.powi(2) * (batch_n_samples as f64 - ddof)
-            + mean_delta.powi(2) * (self.n_samples as f64) * (batch_n_samples as f64)
-                / (new_n_samples as f64))
-            / (new_n_samples as f64 - ddof))
-            .sqrt();
+        // Chan's parallel merge of group A (the scaler's own running moments)
+        // and group B (this batch). Group A is read back from `transformer`,
+        // not from `self`, so the merge is correct even if `self` has never
+        // seen this scaler's history.
+        let delta: Array1<f64> = &batch_mean - &transformer.mean;
+        let new_n_samples = transformer.n_samples + (batch_n_samples as u64);
+        let mean_shift: Array1<f64> = &delta * (batch_n_samples as f64) / (new_n_samples as f64);
+        let new_mean: Array1<f64> = &transformer.mean + &mean_shift;
+        let cross_term: Array1<f64> = delta.mapv(|v| v.powi(2)) * (transformer.n_samples as f64)
+            * (batch_n_samples as f64)
+            / (new_n_samples as f64);
+        let new_m2: Array1<f64> = &transformer.m2 + &batch_m2 + &cross_term;
 
-        // Update n_samples
-        self.n_samples = new_n_samples;
-
-        // Return tuned scaler
+        let standard_deviation = (&new_m2 / (new_n_samples as f64 - ddof)).mapv(f64::sqrt);
         Ok(StandardScaler {
             ddof,
+            target_mean: transformer.target_mean,
+            target_scale: transformer.target_scale,
             mean: new_mean,
-            standard_deviation: new_std,
+            standard_deviation,
+            n_samples: new_n_samples,
+            m2: new_m2,
         })
     }
 }
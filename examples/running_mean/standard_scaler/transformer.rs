@@ -1,8 +1,10 @@
 use crate::standard_scaler::{Input, Output, ScalingError};
 use linfa::Transformer;
-use ndarray::Data;
+use ndarray::{Array1, Data};
 
-/// Given an input, it rescales it to have zero mean and unit variance.
+/// Given a 2-D feature matrix, it rescales every column independently to have
+/// the configured target mean and target scale (zero mean and unit variance
+/// by default).
 ///
 /// We use 64-bit floats for simplicity.
 pub struct StandardScaler {
@@ -10,8 +12,20 @@ pub struct StandardScaler {
     // With ddof = 1, you get the sample standard deviation
     // With ddof = 0, you get the population standard deviation
     pub ddof: f64,
-    pub mean: f64,
-    pub standard_deviation: f64,
+    pub target_mean: f64,
+    pub target_scale: f64,
+    // One entry per column.
+    pub mean: Array1<f64>,
+    pub standard_deviation: Array1<f64>,
+    // The sufficient statistics `OnlineOptimizer` needs to resume
+    // incremental fitting: the sample count feeding `mean`, and `M2`, the
+    // running sum of squared deviations from it (see `OnlineOptimizer`'s
+    // docs). Carried on the scaler itself, rather than on the optimizer,
+    // so that `incremental_fit` is correct even when fed a fresh
+    // `OnlineOptimizer` alongside a previously-fitted `StandardScaler`.
+    pub n_samples: u64,
+    // One entry per column.
+    pub m2: Array1<f64>,
 }
 
 impl<S> Transformer<Input<S>, Output> for StandardScaler
@@ -20,10 +34,24 @@ where
 {
     type Error = ScalingError;
 
-    fn transform(&self, inputs: &Input<S>) -> Result<Output, Self::Error>
-    where
-        S: Data<Elem = f64>,
-    {
-        Ok((inputs - self.mean) / self.standard_deviation)
+    // `inverse_transform` always hands back a freshly-allocated `Output`,
+    // regardless of what storage `S` was used to call `transform`: there's no
+    // single `S` to resurrect once the original array is gone.
+    type Inverted = Output;
+
+    fn transform(&self, inputs: &Input<S>) -> Result<Output, Self::Error> {
+        if inputs.ncols() != self.mean.len() {
+            return Err(ScalingError {});
+        }
+        let standardized = (inputs - &self.mean) / &self.standard_deviation;
+        Ok(standardized * self.target_scale + self.target_mean)
+    }
+
+    fn inverse_transform(&self, outputs: &Output) -> Result<Self::Inverted, Self::Error> {
+        if outputs.ncols() != self.mean.len() {
+            return Err(ScalingError {});
+        }
+        let destandardized = (outputs - self.target_mean) / self.target_scale;
+        Ok(destandardized * &self.standard_deviation + &self.mean)
     }
 }
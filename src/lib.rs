@@ -16,7 +16,24 @@ use std::iter;
 pub trait Transformer<I, O> {
     type Error: error::Error;
 
+    /// What `inverse_transform` produces. Usually just `I`, but a transformer
+    /// whose `I` is generic over storage (e.g. accepts both owned arrays and
+    /// views) still has to land on a single concrete, owned type when it
+    /// reconstructs something on the input side - `I` itself isn't always
+    /// nameable as a return type in that case.
+    type Inverted;
+
     fn transform(&self, inputs: &I) -> Result<O, Self::Error>;
+
+    /// The dual of `transform`: maps outputs back onto the original input
+    /// space, when the transformer's underlying formula can be inverted.
+    ///
+    /// Left unimplemented by default - override it for transformers where
+    /// going back makes sense (e.g. reporting predictions on the original
+    /// scale).
+    fn inverse_transform(&self, _outputs: &O) -> Result<Self::Inverted, Self::Error> {
+        unimplemented!("inverse_transform is not implemented for this transformer")
+    }
 }
 
 /// One step closer to the peak.